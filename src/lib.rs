@@ -18,21 +18,25 @@
 //! This crate implements the WCAG specification for contrast ratio and relative luminance.
 //! Read more about WCAG at [https://www.w3.org/TR/WCAG20/](https://www.w3.org/TR/WCAG20/).
 
+use std::fmt;
 use std::str::FromStr;
 
 ///
-/// A representation for a color with the red, green and blue channels
+/// A representation for a color with the red, green, blue and alpha channels. Colors are opaque
+/// (`a == 255`) unless constructed with [Color::with_alpha] or parsed from a `#RGBA` or
+/// `#RRGGBBAA` hex string.
 ///
 #[derive(Debug, PartialOrd, PartialEq)]
 pub struct Color {
     r: u8,
     g: u8,
     b: u8,
+    a: u8,
 }
 
 impl Color {
     ///
-    /// Creates a new [Color].
+    /// Creates a new opaque [Color].
     /// ```rust
     /// use wcagcontrast::Color;
     /// use std::str::FromStr;
@@ -41,7 +45,20 @@ impl Color {
     /// assert_eq!(color.rgb(), (255, 255, 255));
     /// ```
     pub fn new(r: u8, g: u8, b: u8) -> Self {
-        Color {r, g, b}
+        Color {r, g, b, a: 255}
+    }
+
+    ///
+    /// Creates a new [Color] with an explicit alpha channel, where `0` is fully transparent and
+    /// `255` is fully opaque.
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let color = Color::with_alpha(0, 0, 0, 153);
+    /// assert_eq!(color.rgba(), (0, 0, 0, 153));
+    /// ```
+    pub fn with_alpha(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color {r, g, b, a}
     }
 
     ///
@@ -57,6 +74,48 @@ impl Color {
         (self.r, self.g, self.b)
     }
 
+    ///
+    /// Generates an ([u8], [u8], [u8], [u8]) tuple from the [Color] with the red, green, blue,
+    /// and alpha channels.
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let color = Color::with_alpha(10, 20, 30, 40);
+    /// assert_eq!(color.rgba(), (10, 20, 30, 40));
+    /// ```
+    pub fn rgba(&self) -> (u8, u8, u8, u8) {
+        (self.r, self.g, self.b, self.a)
+    }
+
+    ///
+    /// Composites this (possibly semi-transparent) [Color] over an opaque `background`, using
+    /// straight-alpha "over" compositing: `out = fg * α + bg * (1 - α)` for each channel,
+    /// with `α = a / 255`. WCAG contrast is only defined for opaque colors, so a
+    /// semi-transparent foreground must be composited before calling [Color::contrast_ratio] or
+    /// the conformance helpers.
+    ///
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let black_60_percent = Color::with_alpha(0, 0, 0, 153);
+    /// let white = Color::new(255, 255, 255);
+    /// assert_eq!(black_60_percent.composite_over(&white), Color::new(102, 102, 102));
+    /// ```
+    ///
+    pub fn composite_over(&self, background: &Color) -> Color {
+        let alpha = self.a as f64 / 255.0;
+
+        let composite_channel = |fg: u8, bg: u8| -> u8 {
+            (fg as f64 * alpha + bg as f64 * (1.0 - alpha)).round() as u8
+        };
+
+        Color::new(
+            composite_channel(self.r, background.r),
+            composite_channel(self.g, background.g),
+            composite_channel(self.b, background.b),
+        )
+    }
+
     ///
     /// Calculates the relative luminance, as described on
     /// [https://www.w3.org/TR/WCAG20/#relativeluminancedef](https://www.w3.org/TR/WCAG20/#relativeluminancedef)
@@ -117,23 +176,564 @@ impl Color {
             f64::powf((c + 0.055) / 1.055, 2.4)
         }
     }
+
+    ///
+    /// Converts this [Color] to the CIE [Lab] color space, under the D65 white point, via CIE
+    /// XYZ. This lets callers reason about perceptual lightness, chroma and hue instead of raw
+    /// sRGB channels.
+    ///
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let white = Color::new(255, 255, 255);
+    /// let (l, a, b) = white.to_lab().lab();
+    /// assert!((l - 100.0).abs() < 0.01);
+    /// assert!(a.abs() < 0.01);
+    /// assert!(b.abs() < 0.01);
+    /// ```
+    ///
+    pub fn to_lab(&self) -> Lab {
+        let r = Color::component_relative_luminance(self.r);
+        let g = Color::component_relative_luminance(self.g);
+        let b = Color::component_relative_luminance(self.b);
+
+        let x = 0.4124564 * r + 0.3575761 * g + 0.1804375 * b;
+        let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+        let z = 0.0193339 * r + 0.1191920 * g + 0.9503041 * b;
+
+        Lab::from_xyz(x, y, z)
+    }
+
+    ///
+    /// Converts this [Color] to the CIE [Lch] color space (the polar form of [Lab]).
+    ///
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let white = Color::new(255, 255, 255);
+    /// let (l, c, _h) = white.to_lch().lch();
+    /// assert!((l - 100.0).abs() < 0.01);
+    /// assert!(c.abs() < 0.01);
+    /// ```
+    ///
+    pub fn to_lch(&self) -> Lch {
+        self.to_lab().to_lch()
+    }
+
+    ///
+    /// Calculates the perceptual color difference between this [Color] and `other` using the
+    /// CIEDE2000 formula, as described on
+    /// [http://www2.ece.rochester.edu/~gsharma/ciede2000/](http://www2.ece.rochester.edu/~gsharma/ciede2000/).
+    /// Unlike [Color::contrast_ratio], which measures legibility, `delta_e_2000` measures how
+    /// similar two colors look: roughly, a `delta_e_2000` below 1.0 is imperceptible and above
+    /// 10.0 is clearly a different color.
+    ///
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let color = Color::new(10, 20, 30);
+    /// assert_eq!(color.delta_e_2000(&color), 0.0);
+    /// ```
+    ///
+    pub fn delta_e_2000(&self, other: &Color) -> f64 {
+        self.to_lab().delta_e_2000(&other.to_lab())
+    }
+
+    ///
+    /// Nudges this [Color] toward black or white until its contrast ratio against `background`
+    /// meets or exceeds `target_ratio` (e.g. `4.5` or `7.0` for [Color::passes_aa] /
+    /// [Color::passes_aaa]), returning the adjusted [Color].
+    ///
+    /// Hue and chroma are held fixed in [Lch] space, and only the `L*` (lightness) channel is
+    /// binary-searched toward `0` or `100`, whichever direction increases contrast against
+    /// `background` — contrast ratio is monotonic in relative luminance, so the search
+    /// converges quickly. A fixed-chroma `L* = 0`/`100` endpoint is gamut-clamped (e.g. a
+    /// saturated blue's `L* = 0` end clamps to a dark blue, not true black) and can therefore
+    /// undershoot the contrast that true black or white would reach. If the fixed-chroma
+    /// extreme fails to meet `target_ratio`, this falls back to whichever of true black
+    /// (`0, 0, 0`) or true white (`255, 255, 255`) has the higher contrast against
+    /// `background`, so a reachable `target_ratio` is never missed just because chroma was
+    /// held fixed.
+    ///
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let background = Color::new(255, 255, 255);
+    /// let adjusted = Color::new(200, 200, 200).adjust_for_contrast(&background, 4.5);
+    /// assert!(adjusted.contrast_ratio(&background) >= 4.5);
+    /// ```
+    ///
+    pub fn adjust_for_contrast(&self, background: &Color, target_ratio: f64) -> Color {
+        if self.contrast_ratio(background) >= target_ratio {
+            return Color::new(self.r, self.g, self.b);
+        }
+
+        let (self_l, c, h) = self.to_lch().lch();
+        let black = Lch::new(0.0, c, h).to_color();
+        let white = Lch::new(100.0, c, h).to_color();
+
+        let toward_white = white.contrast_ratio(background) > black.contrast_ratio(background);
+        let extreme = if toward_white { &white } else { &black };
+        if extreme.contrast_ratio(background) < target_ratio {
+            let true_black = Color::new(0, 0, 0);
+            let true_white = Color::new(255, 255, 255);
+            return if true_black.contrast_ratio(background) >= true_white.contrast_ratio(background) {
+                true_black
+            } else {
+                true_white
+            };
+        }
+
+        let (mut lo, mut hi) = if toward_white { (self_l, 100.0) } else { (0.0, self_l) };
+
+        for _ in 0..32 {
+            let mid = (lo + hi) / 2.0;
+            let candidate = Lch::new(mid, c, h).to_color();
+            let passes = candidate.contrast_ratio(background) >= target_ratio;
+
+            match (toward_white, passes) {
+                (true, true) => hi = mid,
+                (true, false) => lo = mid,
+                (false, true) => lo = mid,
+                (false, false) => hi = mid,
+            }
+        }
+
+        let result_l = if toward_white { hi } else { lo };
+        Lch::new(result_l, c, h).to_color()
+    }
+
+    ///
+    /// Converts a linear-light sRGB component back into its gamma-compressed `[0, 255]` form.
+    ///
+    fn component_from_linear(linear_component: f64) -> u8 {
+        let c = if linear_component <= 0.0031308 {
+            linear_component * 12.92
+        } else {
+            1.055 * f64::powf(linear_component, 1.0 / 2.4) - 0.055
+        };
+
+        (c * 255.0).round().clamp(0.0, 255.0) as u8
+    }
+
+    ///
+    /// Checks whether the contrast ratio against `other` meets the WCAG 2.1 AA threshold, as
+    /// described on [https://www.w3.org/TR/WCAG20/#visual-audio-contrast-contrast](https://www.w3.org/TR/WCAG20/#visual-audio-contrast-contrast).
+    /// `large_text` should be `true` for text that is at least 18pt, or 14pt bold.
+    ///
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let black = Color::new(0, 0, 0);
+    /// let white = Color::new(255, 255, 255);
+    /// assert!(black.passes_aa(&white, false));
+    /// ```
+    ///
+    pub fn passes_aa(&self, other: &Color, large_text: bool) -> bool {
+        let threshold = if large_text { 3.0 } else { 4.5 };
+        self.contrast_ratio(other) >= threshold
+    }
+
+    ///
+    /// Checks whether the contrast ratio against `other` meets the WCAG 2.1 AAA threshold, as
+    /// described on [https://www.w3.org/TR/WCAG20/#visual-audio-contrast7](https://www.w3.org/TR/WCAG20/#visual-audio-contrast7).
+    /// `large_text` should be `true` for text that is at least 18pt, or 14pt bold.
+    ///
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let black = Color::new(0, 0, 0);
+    /// let white = Color::new(255, 255, 255);
+    /// assert!(black.passes_aaa(&white, false));
+    /// ```
+    ///
+    pub fn passes_aaa(&self, other: &Color, large_text: bool) -> bool {
+        let threshold = if large_text { 4.5 } else { 7.0 };
+        self.contrast_ratio(other) >= threshold
+    }
+
+    ///
+    /// Checks whether the contrast ratio against `other` meets the WCAG 2.1 threshold for
+    /// UI components and graphical objects, as described on
+    /// [https://www.w3.org/TR/WCAG21/#non-text-contrast](https://www.w3.org/TR/WCAG21/#non-text-contrast).
+    ///
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let black = Color::new(0, 0, 0);
+    /// let white = Color::new(255, 255, 255);
+    /// assert!(black.passes_graphics(&white));
+    /// ```
+    ///
+    pub fn passes_graphics(&self, other: &Color) -> bool {
+        self.contrast_ratio(other) >= 3.0
+    }
+
+    ///
+    /// Classifies the contrast ratio against `other` into the highest [ConformanceLevel] it
+    /// satisfies, checking AAA first and falling back to AA or [ConformanceLevel::Fail].
+    ///
+    /// ```rust
+    /// use wcagcontrast::{Color, ConformanceLevel};
+    ///
+    /// let black = Color::new(0, 0, 0);
+    /// let white = Color::new(255, 255, 255);
+    /// assert_eq!(black.conformance(&white, false), ConformanceLevel::AAA);
+    /// ```
+    ///
+    pub fn conformance(&self, other: &Color, large_text: bool) -> ConformanceLevel {
+        if self.passes_aaa(other, large_text) {
+            ConformanceLevel::AAA
+        } else if self.passes_aa(other, large_text) {
+            ConformanceLevel::AA
+        } else {
+            ConformanceLevel::Fail
+        }
+    }
+}
+
+///
+/// The WCAG 2.1 conformance level a pair of colors achieves for text contrast, as returned by
+/// [Color::conformance].
+///
+#[derive(Debug, PartialOrd, PartialEq)]
+pub enum ConformanceLevel {
+    Fail,
+    AA,
+    AAA,
+}
+
+const XN: f64 = 0.95047;
+const YN: f64 = 1.0;
+const ZN: f64 = 1.08883;
+
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
+
+///
+/// A representation of a color in the CIE Lab color space, with the `l` (lightness), `a`
+/// (green-red) and `b` (blue-yellow) channels. See [Color::to_lab].
+///
+#[derive(Debug, PartialOrd, PartialEq)]
+pub struct Lab {
+    l: f64,
+    a: f64,
+    b: f64,
+}
+
+impl Lab {
+    ///
+    /// Creates a new [Lab] color from its `l`, `a` and `b` channels.
+    ///
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Lab { l, a, b }
+    }
+
+    ///
+    /// Generates an (l, a, b) tuple from the [Lab] color.
+    ///
+    pub fn lab(&self) -> (f64, f64, f64) {
+        (self.l, self.a, self.b)
+    }
+
+    fn from_xyz(x: f64, y: f64, z: f64) -> Self {
+        let fx = Lab::f(x / XN);
+        let fy = Lab::f(y / YN);
+        let fz = Lab::f(z / ZN);
+
+        Lab::new(116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+    }
+
+    fn to_xyz(&self) -> (f64, f64, f64) {
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+
+        (Lab::finv(fx) * XN, Lab::finv(fy) * YN, Lab::finv(fz) * ZN)
+    }
+
+    fn f(t: f64) -> f64 {
+        if t > LAB_EPSILON {
+            f64::powf(t, 1.0 / 3.0)
+        } else {
+            (LAB_KAPPA * t + 16.0) / 116.0
+        }
+    }
+
+    fn finv(t: f64) -> f64 {
+        let cubed = t * t * t;
+
+        if cubed > LAB_EPSILON {
+            cubed
+        } else {
+            (116.0 * t - 16.0) / LAB_KAPPA
+        }
+    }
+
+    ///
+    /// Converts this [Lab] color back into sRGB, clamping out-of-gamut channels to `[0, 255]`.
+    ///
+    /// ```rust
+    /// use wcagcontrast::Color;
+    ///
+    /// let white = Color::new(255, 255, 255);
+    /// assert_eq!(white.to_lab().to_color(), white);
+    /// ```
+    ///
+    pub fn to_color(&self) -> Color {
+        let (x, y, z) = self.to_xyz();
+
+        let r = 3.2404542 * x - 1.5371385 * y - 0.4985314 * z;
+        let g = -0.9692660 * x + 1.8760108 * y + 0.0415560 * z;
+        let b = 0.0556434 * x - 0.2040259 * y + 1.0572252 * z;
+
+        Color::new(
+            Color::component_from_linear(r),
+            Color::component_from_linear(g),
+            Color::component_from_linear(b),
+        )
+    }
+
+    ///
+    /// Converts this [Lab] color to its polar [Lch] form.
+    ///
+    pub fn to_lch(&self) -> Lch {
+        let c = f64::sqrt(self.a * self.a + self.b * self.b);
+        let h = f64::atan2(self.b, self.a).to_degrees();
+        let h = if h < 0.0 { h + 360.0 } else { h };
+
+        Lch::new(self.l, c, h)
+    }
+
+    ///
+    /// Calculates the CIEDE2000 perceptual color difference between this [Lab] color and
+    /// `other`. See [Color::delta_e_2000].
+    ///
+    pub fn delta_e_2000(&self, other: &Lab) -> f64 {
+        let c1 = f64::sqrt(self.a * self.a + self.b * self.b);
+        let c2 = f64::sqrt(other.a * other.a + other.b * other.b);
+        let c_bar = (c1 + c2) / 2.0;
+
+        let c_bar_pow7 = f64::powf(c_bar, 7.0);
+        let g = 0.5 * (1.0 - f64::sqrt(c_bar_pow7 / (c_bar_pow7 + f64::powf(25.0, 7.0))));
+
+        let a1_prime = self.a * (1.0 + g);
+        let a2_prime = other.a * (1.0 + g);
+
+        let c1_prime = f64::sqrt(a1_prime * a1_prime + self.b * self.b);
+        let c2_prime = f64::sqrt(a2_prime * a2_prime + other.b * other.b);
+
+        let h1_prime = Lab::hue_degrees(self.b, a1_prime);
+        let h2_prime = Lab::hue_degrees(other.b, a2_prime);
+
+        let delta_l_prime = other.l - self.l;
+        let delta_c_prime = c2_prime - c1_prime;
+
+        let delta_h_prime = if c1_prime == 0.0 || c2_prime == 0.0 {
+            0.0
+        } else {
+            let diff = h2_prime - h1_prime;
+            if diff.abs() <= 180.0 {
+                diff
+            } else if diff > 180.0 {
+                diff - 360.0
+            } else {
+                diff + 360.0
+            }
+        };
+        let delta_h_capital = 2.0 * f64::sqrt(c1_prime * c2_prime) * f64::sin(delta_h_prime.to_radians() / 2.0);
+
+        let l_bar_prime = (self.l + other.l) / 2.0;
+        let c_bar_prime = (c1_prime + c2_prime) / 2.0;
+
+        let h_bar_prime = if c1_prime == 0.0 || c2_prime == 0.0 {
+            h1_prime + h2_prime
+        } else {
+            let sum = h1_prime + h2_prime;
+            let diff = (h1_prime - h2_prime).abs();
+            if diff <= 180.0 {
+                sum / 2.0
+            } else if sum < 360.0 {
+                (sum + 360.0) / 2.0
+            } else {
+                (sum - 360.0) / 2.0
+            }
+        };
+
+        let t = 1.0
+            - 0.17 * f64::cos((h_bar_prime - 30.0).to_radians())
+            + 0.24 * f64::cos((2.0 * h_bar_prime).to_radians())
+            + 0.32 * f64::cos((3.0 * h_bar_prime + 6.0).to_radians())
+            - 0.20 * f64::cos((4.0 * h_bar_prime - 63.0).to_radians());
+
+        let s_l = 1.0
+            + (0.015 * (l_bar_prime - 50.0) * (l_bar_prime - 50.0))
+                / f64::sqrt(20.0 + (l_bar_prime - 50.0) * (l_bar_prime - 50.0));
+        let s_c = 1.0 + 0.045 * c_bar_prime;
+        let s_h = 1.0 + 0.015 * c_bar_prime * t;
+
+        let delta_theta = 30.0 * f64::exp(-f64::powf((h_bar_prime - 275.0) / 25.0, 2.0));
+        let c_bar_prime_pow7 = f64::powf(c_bar_prime, 7.0);
+        let r_c = 2.0 * f64::sqrt(c_bar_prime_pow7 / (c_bar_prime_pow7 + f64::powf(25.0, 7.0)));
+        let r_t = -f64::sin((2.0 * delta_theta).to_radians()) * r_c;
+
+        let l_term = delta_l_prime / s_l;
+        let c_term = delta_c_prime / s_c;
+        let h_term = delta_h_capital / s_h;
+
+        f64::sqrt(l_term * l_term + c_term * c_term + h_term * h_term + r_t * c_term * h_term)
+    }
+
+    ///
+    /// Computes `atan2(b, a)` in degrees, normalized to `[0, 360)`, treating a zero chroma
+    /// (both components zero) as hue `0`.
+    ///
+    fn hue_degrees(b: f64, a: f64) -> f64 {
+        if a == 0.0 && b == 0.0 {
+            return 0.0;
+        }
+
+        let h = f64::atan2(b, a).to_degrees();
+        if h < 0.0 {
+            h + 360.0
+        } else {
+            h
+        }
+    }
+}
+
+///
+/// A representation of a color in the CIE LCh color space: the polar form of [Lab], with `l`
+/// (lightness), `c` (chroma) and `h` (hue, in degrees). See [Color::to_lch].
+///
+#[derive(Debug, PartialOrd, PartialEq)]
+pub struct Lch {
+    l: f64,
+    c: f64,
+    h: f64,
+}
+
+impl Lch {
+    ///
+    /// Creates a new [Lch] color from its `l`, `c` and `h` channels.
+    ///
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Lch { l, c, h }
+    }
+
+    ///
+    /// Generates an (l, c, h) tuple from the [Lch] color.
+    ///
+    pub fn lch(&self) -> (f64, f64, f64) {
+        (self.l, self.c, self.h)
+    }
+
+    ///
+    /// Converts this [Lch] color to its rectangular [Lab] form.
+    ///
+    pub fn to_lab(&self) -> Lab {
+        let h = self.h.to_radians();
+
+        Lab::new(self.l, self.c * f64::cos(h), self.c * f64::sin(h))
+    }
+
+    ///
+    /// Converts this [Lch] color back into sRGB, clamping out-of-gamut channels to `[0, 255]`.
+    ///
+    pub fn to_color(&self) -> Color {
+        self.to_lab().to_color()
+    }
+}
+
+///
+/// The error returned by [Color]'s [FromStr] implementation when a string is not a valid CSS
+/// hex color.
+///
+#[derive(Debug, PartialEq)]
+pub enum ParseColorError {
+    /// The string (after stripping an optional leading `#`) was not 3, 4, 6 or 8 hex digits long.
+    BadLength(usize),
+    /// The string contained a character that is not a valid hex digit.
+    InvalidDigit,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseColorError::BadLength(len) => write!(
+                f,
+                "expected 3, 4, 6 or 8 hex digits, got {len}"
+            ),
+            ParseColorError::InvalidDigit => write!(f, "invalid hex digit"),
+        }
+    }
 }
 
+impl std::error::Error for ParseColorError {}
+
 impl FromStr for Color {
-    type Err = std::num::ParseIntError;
+    type Err = ParseColorError;
 
+    /// Parses a CSS hex color, with or without a leading `#`. Accepts the shorthand `#RGB` and
+    /// `#RGBA` forms (each nibble expanded, e.g. `0af` becomes `00aaff`) as well as the full
+    /// `#RRGGBB` and `#RRGGBBAA` forms.
+    ///
     /// ```rust
     /// use wcagcontrast::Color;
     /// use std::str::FromStr;
     ///
     /// let color = Color::from_str("#FFFFFF").unwrap();
     /// assert_eq!(color.rgb(), (255, 255, 255));
+    ///
+    /// let color = Color::from_str("0af").unwrap();
+    /// assert_eq!(color.rgb(), (0, 170, 255));
+    ///
+    /// let color = Color::from_str("#0000FF99").unwrap();
+    /// assert_eq!(color.rgba(), (0, 0, 255, 0x99));
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let r = u8::from_str_radix(&s[1 .. 3], 16)?;
-        let g = u8::from_str_radix(&s[3 .. 5], 16)?;
-        let b = u8::from_str_radix(&s[5 .. 7], 16)?;
-        Ok(Color::new(r, g, b))
+        let hex = s.strip_prefix('#').unwrap_or(s);
+
+        if !hex.is_ascii() {
+            return Err(ParseColorError::InvalidDigit);
+        }
+
+        let byte_from_nibble = |c: char| -> Result<u8, ParseColorError> {
+            let d = c.to_digit(16).ok_or(ParseColorError::InvalidDigit)? as u8;
+            Ok(d * 16 + d)
+        };
+        let byte_from_pair = |pair: &str| -> Result<u8, ParseColorError> {
+            if !pair.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(ParseColorError::InvalidDigit);
+            }
+            u8::from_str_radix(pair, 16).map_err(|_| ParseColorError::InvalidDigit)
+        };
+
+        match hex.len() {
+            3 | 4 => {
+                let chars: Vec<char> = hex.chars().collect();
+                let r = byte_from_nibble(chars[0])?;
+                let g = byte_from_nibble(chars[1])?;
+                let b = byte_from_nibble(chars[2])?;
+                let a = match chars.get(3) {
+                    Some(&c) => byte_from_nibble(c)?,
+                    None => 255,
+                };
+                Ok(Color::with_alpha(r, g, b, a))
+            }
+            6 | 8 => {
+                let r = byte_from_pair(&hex[0..2])?;
+                let g = byte_from_pair(&hex[2..4])?;
+                let b = byte_from_pair(&hex[4..6])?;
+                let a = if hex.len() == 8 {
+                    byte_from_pair(&hex[6..8])?
+                } else {
+                    255
+                };
+                Ok(Color::with_alpha(r, g, b, a))
+            }
+            len => Err(ParseColorError::BadLength(len)),
+        }
     }
 }
 
@@ -165,6 +765,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn color_from_hex_accepts_css_shorthand_and_alpha_forms() {
+        assert_eq!(Color::from_str("000000").unwrap(), Color::new(0, 0, 0));
+        assert_eq!(Color::from_str("#0af").unwrap(), Color::new(0, 170, 255));
+        assert_eq!(
+            Color::from_str("#0af8").unwrap(),
+            Color::with_alpha(0, 170, 255, 0x88)
+        );
+        assert_eq!(
+            Color::from_str("#0000FF99").unwrap(),
+            Color::with_alpha(0, 0, 255, 0x99)
+        );
+        assert_eq!(
+            Color::from_str("#1234").unwrap(),
+            Color::with_alpha(0x11, 0x22, 0x33, 0x44)
+        );
+    }
+
+    #[test]
+    fn color_from_hex_rejects_malformed_input() {
+        assert_eq!(Color::from_str(""), Err(ParseColorError::BadLength(0)));
+        assert_eq!(Color::from_str("#12345"), Err(ParseColorError::BadLength(5)));
+        assert_eq!(Color::from_str("#gggggg"), Err(ParseColorError::InvalidDigit));
+        assert_eq!(Color::from_str("éa"), Err(ParseColorError::InvalidDigit));
+    }
+
     #[test]
     fn calculates_correct_ratio() {
         assert_eq!(
@@ -191,4 +817,120 @@ mod test {
         assert_eq!(Color::new(255, 255, 255).relative_luminance(), 1.0);
         assert_eq!(Color::new(0, 0, 0).relative_luminance(), 0.0);
     }
+
+    #[test]
+    fn checks_wcag_conformance_levels() {
+        let black = Color::new(0, 0, 0);
+        let white = Color::new(255, 255, 255);
+
+        assert!(black.passes_aa(&white, false));
+        assert!(black.passes_aaa(&white, false));
+        assert!(black.passes_graphics(&white));
+        assert_eq!(black.conformance(&white, false), ConformanceLevel::AAA);
+
+        let gray = Color::new(130, 130, 130);
+        assert!(gray.passes_aa(&white, true));
+        assert!(!gray.passes_aa(&white, false));
+        assert_eq!(gray.conformance(&white, false), ConformanceLevel::Fail);
+    }
+
+    #[test]
+    fn converts_black_and_white_to_lab() {
+        let black_lab = Color::new(0, 0, 0).to_lab();
+        assert!(black_lab.lab().0.abs() < 0.01);
+
+        let white_lab = Color::new(255, 255, 255).to_lab();
+        let (l, a, b) = white_lab.lab();
+        assert!((l - 100.0).abs() < 0.01);
+        assert!(a.abs() < 0.01);
+        assert!(b.abs() < 0.01);
+    }
+
+    #[test]
+    fn round_trips_rgb_through_lab_and_lch() {
+        let colors = [
+            Color::new(0, 0, 0),
+            Color::new(255, 255, 255),
+            Color::new(255, 0, 0),
+            Color::new(12, 200, 97),
+        ];
+
+        for color in colors {
+            assert_eq!(color.to_lab().to_color(), color);
+            assert_eq!(color.to_lch().to_color(), color);
+
+            let (l1, a1, b1) = color.to_lab().lab();
+            let (l2, a2, b2) = color.to_lab().to_lch().to_lab().lab();
+            assert!((l1 - l2).abs() < 1e-6);
+            assert!((a1 - a2).abs() < 1e-6);
+            assert!((b1 - b2).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn delta_e_2000_is_zero_for_identical_colors() {
+        let color = Color::new(120, 45, 200);
+        assert_eq!(color.delta_e_2000(&color), 0.0);
+    }
+
+    #[test]
+    fn delta_e_2000_grows_with_perceptual_difference() {
+        let red = Color::new(255, 0, 0);
+        let similar_red = Color::new(250, 10, 10);
+        let blue = Color::new(0, 0, 255);
+
+        assert!(red.delta_e_2000(&similar_red) < red.delta_e_2000(&blue));
+    }
+
+    #[test]
+    fn composites_semi_transparent_color_over_background() {
+        let black_60_percent = Color::with_alpha(0, 0, 0, 153);
+        let white = Color::new(255, 255, 255);
+
+        assert_eq!(black_60_percent.composite_over(&white), Color::new(102, 102, 102));
+    }
+
+    #[test]
+    fn compositing_opaque_color_is_a_no_op() {
+        let opaque_red = Color::new(255, 0, 0);
+        let white = Color::new(255, 255, 255);
+
+        assert_eq!(opaque_red.composite_over(&white), opaque_red);
+    }
+
+    #[test]
+    fn adjust_for_contrast_leaves_passing_colors_unchanged() {
+        let white = Color::new(255, 255, 255);
+        let black = Color::new(0, 0, 0);
+
+        assert_eq!(black.adjust_for_contrast(&white, 4.5), black);
+    }
+
+    #[test]
+    fn adjust_for_contrast_nudges_failing_colors_until_they_pass() {
+        let white = Color::new(255, 255, 255);
+        let light_gray = Color::new(200, 200, 200);
+
+        assert!(!light_gray.passes_aa(&white, false));
+
+        let adjusted = light_gray.adjust_for_contrast(&white, 4.5);
+        assert!(adjusted.passes_aa(&white, false));
+    }
+
+    #[test]
+    fn adjust_for_contrast_returns_extreme_when_target_is_unreachable() {
+        let mid_gray = Color::new(128, 128, 128);
+
+        let adjusted = mid_gray.adjust_for_contrast(&mid_gray, 21.0);
+        assert!(adjusted == Color::new(0, 0, 0) || adjusted == Color::new(255, 255, 255));
+    }
+
+    #[test]
+    fn adjust_for_contrast_falls_back_to_true_black_or_white_for_saturated_colors() {
+        let background = Color::new(51, 124, 188);
+        let saturated_blue = Color::new(57, 69, 217);
+
+        let adjusted = saturated_blue.adjust_for_contrast(&background, 4.5);
+        assert!(adjusted.contrast_ratio(&background) >= 4.5);
+    }
 }